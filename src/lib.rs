@@ -6,8 +6,30 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 //! # input-macro - No-nonsense input!(...) macro for Rust.
 //!
+//! ## Features
+//!
+//! - **`std`** *(enabled by default)* — backs [`input_fmt`] and
+//!   [`read_line_expect`] with `std::io`, and enables the `std`-only
+//!   [`input!`], [`scan!`] and [`read_value!`] macros, which need
+//!   `std::io::stdin()`/`stdout()` or construct `std::io::Error` directly.
+//!
+//! Disabling default features drops the `std` dependency and switches
+//! [`input_fmt`]/[`read_line_expect`] over to the equivalent traits from
+//! [`no_std_io`], a minimal in-crate stand-in for `std::io`, so they stay
+//! usable on `no_std` targets that supply their own
+//! [`BufRead`](no_std_io::BufRead)/[`Write`](no_std_io::Write) implementors
+//! (e.g. over a UART). The `input!`, `scan!` and `read_value!` macros are
+//! unavailable without `std`.
+//!
+//! (An earlier draft of this feature shimmed `no_std` support through the
+//! `core_io` crate, but `core_io` only builds on a pinned pre-1.0 nightly
+//! compiler via long-removed `#![feature(...)]` gates, so it cannot compile
+//! on any current stable toolchain — hence the small hand-rolled shim here.)
+//!
 //! # Example
 //!
 //! ```no_run
@@ -39,8 +61,119 @@
 //! }
 //! ```
 
-use std::fmt::Arguments;
-use std::io::{self, BufRead, Write};
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use core::fmt::Arguments;
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+pub use std::io;
+#[cfg(not(feature = "std"))]
+pub use no_std_io as io;
+use io::{BufRead, Write};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+/// A minimal, dependency-free stand-in for `std::io`'s [`BufRead`]/[`Write`]
+/// traits and [`Error`](no_std_io::Error) type, used in place of `std::io`
+/// when the `std` feature is disabled.
+///
+/// Implement [`BufRead`](no_std_io::BufRead) and [`Write`](no_std_io::Write)
+/// for your own reader/writer (e.g. a UART driver) to use [`input_fmt`] and
+/// [`read_line_expect`] on a `no_std` target.
+#[cfg(not(feature = "std"))]
+pub mod no_std_io {
+    use super::Arguments;
+    use alloc::string::String;
+
+    /// Mirrors [`std::io::Error`]: a coarse [`ErrorKind`] plus a message.
+    #[derive(Debug)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: String,
+    }
+
+    impl Error {
+        pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+            Error {
+                kind,
+                message: message.into(),
+            }
+        }
+
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl core::fmt::Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.message)
+        }
+    }
+
+    /// Mirrors the handful of [`std::io::ErrorKind`] variants this crate needs.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ErrorKind {
+        UnexpectedEof,
+        Other,
+    }
+
+    /// Mirrors [`std::io::Result`].
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// A `no_std` stand-in for [`std::io::Write`]; implement this for your
+    /// own writer (e.g. a UART driver) to use [`input_fmt`](crate::input_fmt).
+    pub trait Write {
+        fn write_fmt(&mut self, args: Arguments<'_>) -> Result<()>;
+        fn flush(&mut self) -> Result<()>;
+    }
+
+    /// A `no_std` stand-in for [`std::io::BufRead`]; implement this for your
+    /// own reader (e.g. a UART driver) to use [`read_line_expect`](crate::read_line_expect).
+    pub trait BufRead {
+        /// Reads the next line (including its line ending) into `buf`,
+        /// returning the number of bytes read, or `0` at EOF.
+        fn read_line(&mut self, buf: &mut String) -> Result<usize>;
+
+        /// Mirrors [`std::io::BufRead::lines`]: an iterator over lines with
+        /// line endings stripped.
+        fn lines(&mut self) -> Lines<'_, Self>
+        where
+            Self: Sized,
+        {
+            Lines { src: self }
+        }
+    }
+
+    /// Iterator returned by [`BufRead::lines`].
+    pub struct Lines<'a, B: ?Sized> {
+        src: &'a mut B,
+    }
+
+    impl<'a, B: BufRead + ?Sized> Iterator for Lines<'a, B> {
+        type Item = Result<String>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let mut buf = String::new();
+            match self.src.read_line(&mut buf) {
+                Ok(0) => None,
+                Ok(_) => {
+                    if buf.ends_with('\n') {
+                        buf.pop();
+                        if buf.ends_with('\r') {
+                            buf.pop();
+                        }
+                    }
+                    Some(Ok(buf))
+                }
+                Err(e) => Some(Err(e)),
+            }
+        }
+    }
+}
 
 /// Displays formatted prompt text to the standard output and
 /// then reads the next line from the standard input,
@@ -51,12 +184,16 @@ use std::io::{self, BufRead, Write};
 /// Panics if writing to `std::io::stdout()` fails,
 /// or reading from `std::io::stdin()` fails.
 ///
+/// Calling `input!(T)` or `input!(T, ...)` with a type `T: FromStr` instead
+/// reads, parses and re-prompts on a failed parse (see [`input_parse_fmt`]),
+/// rather than handing back the raw [`String`].
+///
 /// # Example
 /// ```no_run
 /// use input_macro::input;
 ///
 /// let name: String = input!("What's your name? ");
-/// let age: i64 = input!("How old are you today {name}? ").parse().unwrap();
+/// let age: i64 = input!(i64, "How old are you today {name}? ");
 /// println!(
 ///     "In hexadecimal, thats {}{:x}!",
 ///     if age < 0 { "-" } else { "" }, age.abs(),
@@ -65,13 +202,41 @@ use std::io::{self, BufRead, Write};
 #[macro_export]
 macro_rules! input {
     () => ($crate::read_line_expect(&mut ::std::io::stdin().lock()).unwrap());
+    ($ty:ty) => ($crate::input_parse_fmt::<_, _, $ty>(&mut ::std::io::stdin().lock(), &mut ::std::io::stdout(), format_args!("")).unwrap());
+    ($ty:ty, $($arg:tt)*) => ($crate::input_parse_fmt::<_, _, $ty>(&mut ::std::io::stdin().lock(), &mut ::std::io::stdout(), format_args!($($arg)*)).unwrap());
     ($($arg:tt)*) => ($crate::input_fmt(&mut ::std::io::stdin().lock(), &mut ::std::io::stdout(), format_args!($($arg)*)).unwrap());
 }
 
+/// Like [`input!`], but returns `None` cleanly at EOF instead of panicking,
+/// by way of [`input_opt_fmt`]. Genuine I/O errors still panic.
+///
+/// # Panics
+///
+/// Panics if writing to `std::io::stdout()` fails,
+/// or reading from `std::io::stdin()` fails for a reason other than EOF.
+///
+/// # Example
+/// ```no_run
+/// use input_macro::input_opt;
+///
+/// while let Some(line) = input_opt!("next> ") {
+///     println!("you said: {line}");
+/// }
+/// println!("bye!");
+/// ```
+#[macro_export]
+macro_rules! input_opt {
+    () => ($crate::input_opt_fmt(&mut ::std::io::stdin().lock(), &mut ::std::io::stdout(), format_args!("")).unwrap());
+    ($($arg:tt)*) => ($crate::input_opt_fmt(&mut ::std::io::stdin().lock(), &mut ::std::io::stdout(), format_args!($($arg)*)).unwrap());
+}
+
 /// Writes and flushes a formatted string as prompt text to the `dst` ([`Write`])
 /// then reads the next line from the `src` ([`io::BufRead`]),
 /// returning it as a [`io::Result<String>`].
 ///
+/// Available without `std` (backed by [`no_std_io`]'s [`BufRead`]/[`Write`])
+/// as long as the `std` feature is disabled.
+///
 /// # Errors
 ///
 /// This function will return any I/O error reported while formatting, flushing or reading.
@@ -98,9 +263,145 @@ pub fn input_fmt<B: BufRead, W: Write>(
     read_line_expect(src)
 }
 
+/// Writes and flushes a formatted string as prompt text to the `dst` ([`Write`])
+/// then reads the next line from the `src` ([`io::BufRead`]), returning
+/// `Ok(None)` cleanly at EOF instead of an [`io::ErrorKind::UnexpectedEof`] error.
+///
+/// # Errors
+///
+/// This function will return any I/O error reported while formatting, flushing or reading,
+/// other than reaching EOF, which is reported as `Ok(None)`.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use input_macro::input_opt_fmt;
+///
+/// let mut source = Cursor::new("Joe Bloggs\n");
+/// let mut output = Vec::new();
+/// let name = input_opt_fmt(&mut source, &mut output, format_args!("What's your name? "));
+/// assert_eq!(name.unwrap(), Some("Joe Bloggs".to_string()));
+///
+/// let name = input_opt_fmt(&mut source, &mut output, format_args!("What's your name? "));
+/// assert_eq!(name.unwrap(), None);
+/// ```
+pub fn input_opt_fmt<B: BufRead, W: Write>(
+    src: &mut B,
+    dst: &mut W,
+    fmt: Arguments,
+) -> io::Result<Option<String>> {
+    match input_fmt(src, dst, fmt) {
+        Ok(line) => Ok(Some(line)),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Writes and flushes a formatted string as prompt text to the `dst` ([`Write`]),
+/// then reads and parses lines from the `src` ([`io::BufRead`]) as a `T`,
+/// re-prompting on a failed parse until one succeeds.
+///
+/// # Errors
+///
+/// This function will return any I/O error reported while formatting, flushing or reading,
+/// including an [`io::ErrorKind::UnexpectedEof`] error if the stream reaches EOF.
+/// A value that fails to `parse` does *not* return an error, it re-prompts instead.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use input_macro::input_parse_fmt;
+///
+/// let mut source = Cursor::new("not a number\n42\n");
+/// let mut output = Vec::new();
+/// let age = input_parse_fmt::<_, _, u8>(&mut source, &mut output, format_args!("How old? "));
+/// assert_eq!(age.unwrap(), 42);
+/// ```
+pub fn input_parse_fmt<B: BufRead, W: Write, T: FromStr>(
+    src: &mut B,
+    dst: &mut W,
+    fmt: Arguments,
+) -> io::Result<T> {
+    loop {
+        let line = input_fmt(src, dst, fmt)?;
+        match line.parse::<T>() {
+            Ok(value) => return Ok(value),
+            Err(_) => writeln!(dst, "Sorry, that didn't parse. Please try again.")?,
+        }
+    }
+}
+
+/// Builds a [`Prompts`] iterator that re-emits a formatted prompt to `dst`
+/// ([`Write`]) and yields each successive line from `src` ([`io::BufRead`]),
+/// stopping cleanly at EOF.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use input_macro::prompts;
+///
+/// let mut source = Cursor::new("one\ntwo\n");
+/// let mut output = Vec::new();
+/// let lines: Vec<String> = prompts(&mut source, &mut output, format_args!("> "))
+///     .collect::<std::io::Result<_>>()
+///     .unwrap();
+/// assert_eq!(lines, vec!["one".to_string(), "two".to_string()]);
+/// ```
+pub fn prompts<'a, B: BufRead, W: Write>(
+    src: &'a mut B,
+    dst: &'a mut W,
+    fmt: Arguments<'a>,
+) -> Prompts<'a, B, W> {
+    Prompts {
+        src,
+        dst,
+        fmt,
+        done: false,
+    }
+}
+
+/// Iterator returned by [`prompts`]. Re-emits its prompt before each line
+/// read from `src`, yielding `Some(Ok(line))` per line and `None` at EOF.
+///
+/// # Errors
+///
+/// Yields `Some(Err(_))` for any I/O error encountered while formatting,
+/// flushing or reading, then fuses: every call after that (or after the call
+/// that reached EOF) returns `None` without touching `src`/`dst` again.
+pub struct Prompts<'a, B, W> {
+    src: &'a mut B,
+    dst: &'a mut W,
+    fmt: Arguments<'a>,
+    done: bool,
+}
+
+impl<'a, B: BufRead, W: Write> Iterator for Prompts<'a, B, W> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match input_opt_fmt(self.src, self.dst, self.fmt) {
+            Ok(Some(line)) => Some(Ok(line)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
 /// Reads the next line from `src` ([`io::BufRead`]), mapping
 /// EOF to [`io::ErrorKind::UnexpectedEof`] and returning a [`io::Result<String>`].
 ///
+/// Available without `std` (backed by [`no_std_io`]'s [`BufRead`]) as long
+/// as the `std` feature is disabled.
+///
 /// # Errors
 ///
 /// This function will return any I/O error reported while reading.
@@ -109,10 +410,10 @@ pub fn input_fmt<B: BufRead, W: Write>(
 /// # Example
 /// ```
 /// use std::io::Cursor;
-/// use input_macro::read_line_expected;
+/// use input_macro::read_line_expect;
 ///
 /// let mut source = Cursor::new("Insert Text Here\n");
-/// let text = read_line_expected(&mut source);
+/// let text = read_line_expect(&mut source);
 /// assert_eq!(text.unwrap(), "Insert Text Here");
 /// ```
 pub fn read_line_expect<B: BufRead>(src: &mut B) -> io::Result<String> {
@@ -124,3 +425,95 @@ pub fn read_line_expect<B: BufRead>(src: &mut B) -> io::Result<String> {
         |line| line,
     )
 }
+
+/// Writes and flushes a formatted string as prompt text to the `dst` ([`Write`]),
+/// reads one line from the `src` ([`io::BufRead`]), splits it on whitespace, and
+/// binds each token to a typed variable, e.g. `scan!(src, dst, "x y: "; x: i64, y: i64)`.
+///
+/// Each `name: type` spec is parsed by [`read_value!`](crate::read_value), which
+/// also understands `(A, B, C)` tuples, `[T; n]` fixed-size reads and `[T]` reads
+/// that first consume a leading count token.
+///
+/// This macro expands to a sequence of `let` statements, so it must be invoked
+/// from a function (or block) returning [`io::Result`], e.g. with `?` on the
+/// line read and on every token parse.
+///
+/// Like [`input!`], this macro is `std`-only: its error construction goes
+/// through `std::io` directly, so it is unavailable without the `std` feature.
+///
+/// # Errors
+///
+/// Propagates any I/O error from reading or writing, an
+/// [`io::ErrorKind::UnexpectedEof`] error if a token is missing, and an
+/// [`io::ErrorKind::InvalidData`] error if a token fails to parse.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use input_macro::scan;
+///
+/// fn read_point(mut src: Cursor<&str>) -> std::io::Result<(i64, i64)> {
+///     let mut output = Vec::new();
+///     scan!(&mut src, &mut output, "x y: "; x: i64, y: i64);
+///     Ok((x, y))
+/// }
+///
+/// assert_eq!(read_point(Cursor::new("3 4\n")).unwrap(), (3, 4));
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! scan {
+    ($src:expr, $dst:expr, $fmt:expr; $($name:ident : $ty:tt),+ $(,)?) => {
+        let __input_macro_line = $crate::input_fmt($src, $dst, format_args!($fmt))?;
+        let mut __input_macro_tokens = __input_macro_line.split_whitespace();
+        let mut __input_macro_next = || -> ::std::io::Result<&str> {
+            __input_macro_tokens.next().ok_or_else(|| {
+                ::std::io::Error::new(::std::io::ErrorKind::UnexpectedEof, "missing token")
+            })
+        };
+        $( let $name = $crate::read_value!(&mut __input_macro_next, $ty); )+
+    };
+}
+
+/// Parses one value out of a `next: FnMut() -> io::Result<&str>` token source,
+/// according to a small type grammar. Used by [`scan!`](crate::scan) to turn
+/// whitespace-separated tokens into typed bindings.
+///
+/// - `read_value!(next, T)` calls `next()?.parse::<T>()`.
+/// - `read_value!(next, (A, B, C))` expands to a tuple of recursive reads.
+/// - `read_value!(next, [T; n])` collects `n` parsed values into a [`Vec<T>`].
+/// - `read_value!(next, [T])` reads a leading `usize` count token, then that
+///   many `T` values into a [`Vec<T>`].
+///
+/// Must be invoked from a function (or block) returning [`io::Result`]; a
+/// missing token or a failed parse returns an `io::Error` via `?` rather than
+/// panicking.
+///
+/// Like [`scan!`], this macro is `std`-only and unavailable without the
+/// `std` feature.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! read_value {
+    ($next:expr, ( $($t:tt),+ $(,)? )) => {
+        ( $( $crate::read_value!($next, $t) ),+ )
+    };
+    ($next:expr, [$t:tt; $n:expr]) => {{
+        let mut __input_macro_values = ::std::vec::Vec::with_capacity($n);
+        for _ in 0..$n {
+            __input_macro_values.push($crate::read_value!($next, $t));
+        }
+        __input_macro_values
+    }};
+    ($next:expr, [$t:tt]) => {{
+        let __input_macro_count: usize = $crate::read_value!($next, usize);
+        $crate::read_value!($next, [$t; __input_macro_count])
+    }};
+    ($next:expr, $t:ty) => {
+        $next()?.parse::<$t>().map_err(|_| {
+            ::std::io::Error::new(
+                ::std::io::ErrorKind::InvalidData,
+                concat!("failed to parse token as `", stringify!($t), "`"),
+            )
+        })?
+    };
+}
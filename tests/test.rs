@@ -1,4 +1,6 @@
-use input_macro::{input, input_fmt, read_line_expect};
+use input_macro::{
+    input, input_fmt, input_opt, input_opt_fmt, input_parse_fmt, prompts, read_line_expect, scan,
+};
 use std::io::{self, BufRead, Cursor};
 
 #[cfg(test)]
@@ -7,6 +9,11 @@ fn input_macro_usage() {
     input!();
     input!("ABC");
     input!("ABC {}", 123);
+    let _x: u8 = input!(u8);
+    let _y: i64 = input!(i64, "ABC {}", 123);
+    input_opt!();
+    input_opt!("ABC");
+    input_opt!("ABC {}", 123);
 }
 
 fn input_fmt_generic<B: BufRead>(src: &mut B) -> io::Result<String> {
@@ -45,3 +52,111 @@ fn read_line_expect_eof() {
     let text = read_line_expect(&mut Cursor::new(""));
     assert_eq!(text.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
 }
+
+#[test]
+fn input_parse_fmt_immediate() {
+    let mut output = Vec::new();
+    let age = input_parse_fmt::<_, _, u8>(&mut Cursor::new("42\n"), &mut output, format_args!("Age: "));
+    assert_eq!(age.unwrap(), 42);
+}
+
+#[test]
+fn input_parse_fmt_reprompts_on_bad_input() {
+    let mut output = Vec::new();
+    let age = input_parse_fmt::<_, _, u8>(
+        &mut Cursor::new("not a number\n-1\n42\n"),
+        &mut output,
+        format_args!("Age: "),
+    );
+    assert_eq!(age.unwrap(), 42);
+    assert_eq!(output.iter().filter(|&&b| b == b':').count(), 3);
+}
+
+#[test]
+fn input_parse_fmt_eof() {
+    let mut output = Vec::new();
+    let age = input_parse_fmt::<_, _, u8>(&mut Cursor::new(""), &mut output, format_args!("Age: "));
+    assert_eq!(age.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+}
+
+fn scan_two(mut src: Cursor<&str>) -> io::Result<(i64, i64)> {
+    let mut output = Vec::new();
+    scan!(&mut src, &mut output, "x y: "; x: i64, y: i64);
+    Ok((x, y))
+}
+
+#[test]
+fn scan_tuple() {
+    assert_eq!(scan_two(Cursor::new("3 4\n")).unwrap(), (3, 4));
+}
+
+fn scan_missing_token(mut src: Cursor<&str>) -> io::Result<i64> {
+    let mut output = Vec::new();
+    scan!(&mut src, &mut output, "x y: "; x: i64, y: i64);
+    Ok(x + y)
+}
+
+#[test]
+fn scan_missing_token_is_eof() {
+    let err = scan_missing_token(Cursor::new("3\n")).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+fn scan_bad_token(mut src: Cursor<&str>) -> io::Result<i64> {
+    let mut output = Vec::new();
+    scan!(&mut src, &mut output, "x: "; x: i64);
+    Ok(x)
+}
+
+#[test]
+fn scan_bad_token_is_invalid_data() {
+    let err = scan_bad_token(Cursor::new("abc\n")).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+fn scan_array(mut src: Cursor<&str>) -> io::Result<Vec<f64>> {
+    let mut output = Vec::new();
+    scan!(&mut src, &mut output, "v: "; v: [f64; 3]);
+    Ok(v)
+}
+
+#[test]
+fn scan_fixed_array() {
+    assert_eq!(scan_array(Cursor::new("1.5 2.5 3.5\n")).unwrap(), vec![1.5, 2.5, 3.5]);
+}
+
+fn scan_counted_vec(mut src: Cursor<&str>) -> io::Result<Vec<i64>> {
+    let mut output = Vec::new();
+    scan!(&mut src, &mut output, "v: "; v: [i64]);
+    Ok(v)
+}
+
+#[test]
+fn scan_leading_count_vec() {
+    assert_eq!(scan_counted_vec(Cursor::new("3 1 2 3\n")).unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn input_opt_fmt_line() {
+    let mut output = Vec::new();
+    let line = input_opt_fmt(&mut Cursor::new("Insert Text Here\n"), &mut output, format_args!("Prompt: "));
+    assert_eq!(String::from_utf8(output).unwrap(), "Prompt: ");
+    assert_eq!(line.unwrap(), Some("Insert Text Here".to_string()));
+}
+
+#[test]
+fn input_opt_fmt_eof() {
+    let mut output = Vec::new();
+    let line = input_opt_fmt(&mut Cursor::new(""), &mut output, format_args!("Prompt: "));
+    assert_eq!(line.unwrap(), None);
+}
+
+#[test]
+fn prompts_yields_until_eof() {
+    let mut source = Cursor::new("one\ntwo\n");
+    let mut output = Vec::new();
+    let lines: io::Result<Vec<String>> =
+        prompts(&mut source, &mut output, format_args!("> ")).collect();
+    assert_eq!(lines.unwrap(), vec!["one".to_string(), "two".to_string()]);
+    assert_eq!(String::from_utf8(output).unwrap(), "> > > ");
+}